@@ -0,0 +1,166 @@
+//! An in-repo Poseidon-challenge EdDSA variant, **not cross-compatible with
+//! circomlib**.
+//!
+//! This module was written taking inspiration from iden3/circomlib's
+//! `EdDSAPoseidonVerifier` template, which computes its Fiat-Shamir challenge
+//! as `Poseidon(R.x, R.y, A.x, A.y, msg)` directly over field elements,
+//! rather than the big-endian byte concatenation `challenge_hash` uses for
+//! the native scheme in [`crate`]. `sign_circom`/`verify_circom` reproduce
+//! that challenge equation and otherwise mirror circomlib's key-clamping and
+//! nonce-derivation *structure* (see their doc comments), but none of that
+//! makes a key or signature produced here usable with circomlib:
+//!
+//! - This whole crate, including this module, is built over
+//!   `ark_ed_on_bls12_381` — Zcash/Sapling Jubjub over BLS12-381's scalar
+//!   field (see `lib.rs`). circomlib's `EdDSAPoseidonVerifier` circuit is
+//!   built over **Baby Jubjub, over BN254's scalar field** — a different
+//!   curve, different `a`/`d` constants, and a different prime entirely. A
+//!   point or scalar produced by this module isn't even an element of the
+//!   field circomlib's circuit operates over, so it cannot be fed to a real
+//!   `EdDSAPoseidonVerifier` instance no matter how the challenge is shaped.
+//! - Even setting the curve mismatch aside, key expansion here reuses this
+//!   crate's own BLAKE3-based [`EdDSAPrivateKey::hash_blake`] rather than the
+//!   Blake-512 circomlib actually uses, so seeds wouldn't derive matching
+//!   keys either.
+//!
+//! In short: this is a second, Poseidon-challenge EdDSA scheme that lives
+//! alongside the native one in this crate, useful if *this crate* is the
+//! only thing on both ends of a proof (e.g. an in-repo Poseidon-friendly
+//! circuit written against Jubjub/BLS12-381). It is not, and cannot
+//! currently be made, interoperable with circomlib/iden3 tooling or keys.
+//! True circomlib interop would require a separate implementation over
+//! `ark_ed_on_bn254` with circomlib's actual Poseidon parameterization.
+
+use crate::{
+    bigint_to_field, convert_base_to_scalar, field_to_bigint, Affine, BaseField, EdDSAPrivateKey,
+    EdDSAPublicKey, EdDSASignature, ScalarField,
+};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use num_bigint::BigUint;
+use poseidon2::poseidon_n;
+
+/// Computes the circomlib-style Fiat-Shamir challenge
+/// `Poseidon(R.x, R.y, A.x, A.y, msg)` over field elements directly. See the
+/// module docs for why this does not make signatures circomlib-compatible.
+fn challenge_hash_circom(message: BaseField, nonce_r: Affine, pk: Affine) -> BaseField {
+    let inputs = vec![
+        field_to_bigint(nonce_r.x),
+        field_to_bigint(nonce_r.y),
+        field_to_bigint(pk.x),
+        field_to_bigint(pk.y),
+        field_to_bigint(message),
+    ];
+
+    let result = poseidon_n(&inputs).expect("Poseidon hash failed");
+    bigint_to_field(&result)
+}
+
+/// Derives the per-message nonce the way circomlib does: hash the high half
+/// of the expanded private key together with the message bytes. Unlike
+/// [`EdDSAPrivateKey::sign`]'s nonce derivation, there is no extra
+/// domain-separation tag mixed in, since circomlib's own derivation has none.
+fn circom_nonce(nonce_secret_bytes: &[u8], message: BaseField) -> ScalarField {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(nonce_secret_bytes);
+    hasher.update(&message.into_bigint().to_bytes_be());
+
+    let mut r = hasher.finalize_xof();
+    let mut output = [0u8; 64];
+    r.fill(&mut output);
+
+    ScalarField::from_be_bytes_mod_order(&output)
+}
+
+impl EdDSAPrivateKey {
+    /// Signs a message using this crate's Poseidon-challenge EdDSA variant.
+    ///
+    /// Key clamping follows circomlib's `pruneBuffer` layout and the nonce
+    /// follows circomlib's hash-the-high-half-plus-message layout, and the
+    /// challenge hash matches circomlib's Poseidon-over-field-elements
+    /// equation — but see the module docs: this crate operates over a
+    /// different curve and field than circomlib's `EdDSAPoseidonVerifier`
+    /// circuit, so none of that structural similarity makes signatures
+    /// produced here usable with circomlib.
+    pub fn sign_circom(&self, message: BaseField) -> EdDSASignature {
+        let out = self.hash_blake();
+        let sk = Self::derive_sk(&out);
+
+        let r_scalar = circom_nonce(&out[32..64], message);
+
+        let nonce_point = (Affine::generator() * r_scalar).into_affine();
+        let pk = (Affine::generator() * sk).into_affine();
+
+        let challenge = challenge_hash_circom(message, nonce_point, pk);
+        let c_scalar = convert_base_to_scalar(challenge);
+
+        let s = r_scalar + (c_scalar * sk);
+        EdDSASignature { r: nonce_point, s }
+    }
+}
+
+impl EdDSAPublicKey {
+    /// Verifies a signature produced by [`EdDSAPrivateKey::sign_circom`].
+    pub fn verify_circom(&self, message: BaseField, signature: &EdDSASignature) -> bool {
+        let s_biguint: BigUint = signature.s.into();
+        if s_biguint >= ScalarField::MODULUS.into() {
+            return false;
+        }
+
+        if self.pk.is_zero() || !self.pk.is_on_curve() || !signature.r.is_on_curve() {
+            return false;
+        }
+
+        let challenge = challenge_hash_circom(message, signature.r, self.pk);
+        let c = convert_base_to_scalar(challenge);
+
+        let s_times_g = Affine::generator() * signature.s;
+        let c_times_pk = self.pk * c;
+
+        let mut result = s_times_g - signature.r - c_times_pk;
+
+        // Multiply by cofactor (8), same cofactored check as `verify`.
+        result.double_in_place();
+        result.double_in_place();
+        result.double_in_place();
+
+        result.is_zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::UniformRand;
+
+    #[test]
+    fn test_sign_circom_and_verify_circom_flow() {
+        let mut rng = rand::thread_rng();
+
+        let sk = EdDSAPrivateKey::random(&mut rng);
+        let pk = sk.public();
+        let message = BaseField::rand(&mut rng);
+
+        let signature = sk.sign_circom(message);
+        assert!(pk.verify_circom(message, &signature));
+
+        let bad_message = BaseField::rand(&mut rng);
+        assert!(!pk.verify_circom(bad_message, &signature));
+    }
+
+    #[test]
+    fn test_sign_circom_differs_from_native_challenge() {
+        // The two schemes must not be interchangeable: a native signature
+        // should not verify under the circom challenge and vice versa.
+        let mut rng = rand::thread_rng();
+        let sk = EdDSAPrivateKey::random(&mut rng);
+        let pk = sk.public();
+        let message = BaseField::rand(&mut rng);
+
+        let native_sig = sk.sign(message);
+        assert!(!pk.verify_circom(message, &native_sig));
+
+        let circom_sig = sk.sign_circom(message);
+        assert!(!pk.verify(message, &circom_sig));
+    }
+}
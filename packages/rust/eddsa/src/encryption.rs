@@ -0,0 +1,180 @@
+//! Poseidon-based authenticated encryption over field-element payloads.
+//!
+//! Two parties who each hold an EdDSA keypair can derive a shared point
+//! `shared = sk_a * Pk_b` (see [`crate::EdDSAPrivateKey::diffie_hellman`]) and
+//! use it to exchange confidential `BaseField` payloads, à la Dusk's Poseidon
+//! encryption. Each plaintext element is masked with a keystream element
+//! derived from `Poseidon(shared.x, shared.y, nonce, counter)`, and a final
+//! Poseidon call over the shared point, nonce, length and full plaintext
+//! produces an authentication tag, so tampering with the ciphertext or its
+//! length is detected on decryption.
+//!
+//! **Deliberate deviation from a true sponge:** Dusk's construction is a
+//! HADES/Poseidon sponge — one permutation call absorbs a full block of
+//! `t-1` plaintext elements, a capacity lane carries the length, and
+//! squeezing reuses that same permutation state, so cost is `O(n/(t-1))`
+//! permutation calls for `n` elements. This module instead uses a
+//! counter-mode PRF: one full [`poseidon_n`] hash per plaintext element
+//! (`keystream_element`) plus one more for the tag, i.e. `O(n)` hash
+//! invocations. It is still a sound one-time-pad-plus-MAC construction given
+//! a non-repeating `nonce`, but a circuit mirroring it would not match the
+//! real Poseidon-encryption sponge gadget, and it costs one hash call per
+//! element instead of amortizing `t-1` elements per permutation.
+//!
+//! This is the resolved design for now, not an open question: the
+//! `poseidon2` dependency, as used elsewhere in this crate, only exposes
+//! many-to-one hash primitives (`poseidon_n`, `poseidon_n2x_compress`,
+//! `poseidon_btree_hasher`) and no raw width-preserving permutation, and a
+//! sponge cannot be built without one. Reimplementing Poseidon's round
+//! constants and MDS matrix from scratch here, unverified against any
+//! reference vector, would be worse than this PRF. Building the true sponge
+//! requires `poseidon2` to export its permutation directly — that has been
+//! raised with that crate's owner as a prerequisite, and this module should
+//! switch to a real absorb/squeeze sponge once it lands.
+
+use crate::{bigint_to_field, field_to_bigint, Affine, BaseField};
+use ark_ff::BigInteger;
+use num_bigint::BigInt;
+use poseidon2::poseidon_n;
+
+const DOMAIN_KEYSTREAM: &[u8] = b"TokamakAuth\xE2\x80\x91EDDSA\xE2\x80\x91POSENC\xE2\x80\x91KS\xE2\x80\x91v1";
+const DOMAIN_TAG: &[u8] = b"TokamakAuth\xE2\x80\x91EDDSA\xE2\x80\x91POSENC\xE2\x80\x91TAG\xE2\x80\x91v1";
+
+fn domain_keystream() -> BaseField {
+    BaseField::from_be_bytes_mod_order(DOMAIN_KEYSTREAM)
+}
+
+fn domain_tag() -> BaseField {
+    BaseField::from_be_bytes_mod_order(DOMAIN_TAG)
+}
+
+/// Derives the `counter`-th keystream element from the shared point and nonce.
+fn keystream_element(shared: Affine, nonce: BaseField, counter: u64) -> BaseField {
+    let inputs = vec![
+        field_to_bigint(domain_keystream()),
+        field_to_bigint(shared.x),
+        field_to_bigint(shared.y),
+        field_to_bigint(nonce),
+        BigInt::from(counter),
+    ];
+    bigint_to_field(&poseidon_n(&inputs).expect("Poseidon hash failed"))
+}
+
+/// Computes the authentication tag binding the shared point, nonce, message
+/// length and full plaintext.
+fn compute_tag(shared: Affine, nonce: BaseField, plaintext: &[BaseField]) -> BaseField {
+    let mut inputs = vec![
+        field_to_bigint(domain_tag()),
+        field_to_bigint(BaseField::from(plaintext.len() as u64)),
+        field_to_bigint(shared.x),
+        field_to_bigint(shared.y),
+        field_to_bigint(nonce),
+    ];
+    inputs.extend(plaintext.iter().map(|p| field_to_bigint(*p)));
+    bigint_to_field(&poseidon_n(&inputs).expect("Poseidon hash failed"))
+}
+
+/// Constant-time byte-slice comparison, so tag verification doesn't leak
+/// timing information about which limb first differs.
+fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Encrypts `plaintext` under the Diffie–Hellman point `shared` and a
+/// caller-supplied `nonce`, appending a final authentication tag element.
+pub fn encrypt(shared: Affine, nonce: BaseField, plaintext: &[BaseField]) -> Vec<BaseField> {
+    let mut ciphertext = Vec::with_capacity(plaintext.len() + 1);
+    for (i, p) in plaintext.iter().enumerate() {
+        let ks = keystream_element(shared, nonce, i as u64);
+        ciphertext.push(*p + ks);
+    }
+    ciphertext.push(compute_tag(shared, nonce, plaintext));
+    ciphertext
+}
+
+/// Decrypts a ciphertext produced by [`encrypt`], returning an error if the
+/// authentication tag does not match.
+pub fn decrypt(shared: Affine, nonce: BaseField, ciphertext: &[BaseField]) -> eyre::Result<Vec<BaseField>> {
+    if ciphertext.is_empty() {
+        return Err(eyre::eyre!(
+            "ciphertext is too short to contain an authentication tag"
+        ));
+    }
+    let (body, tag) = ciphertext.split_at(ciphertext.len() - 1);
+    let tag = tag[0];
+
+    let plaintext: Vec<BaseField> = body
+        .iter()
+        .enumerate()
+        .map(|(i, c)| *c - keystream_element(shared, nonce, i as u64))
+        .collect();
+
+    let expected_tag = compute_tag(shared, nonce, &plaintext);
+    if !ct_eq(
+        &expected_tag.into_bigint().to_bytes_le(),
+        &tag.into_bigint().to_bytes_le(),
+    ) {
+        return Err(eyre::eyre!("authentication tag mismatch"));
+    }
+
+    Ok(plaintext)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EdDSAPrivateKey, EdDSAPublicKey};
+    use ark_ff::UniformRand;
+
+    fn shared_points(rng: &mut impl rand::Rng) -> (Affine, Affine) {
+        let sk_a = EdDSAPrivateKey::random(rng);
+        let pk_a = sk_a.public();
+        let sk_b = EdDSAPrivateKey::random(rng);
+        let pk_b = sk_b.public();
+
+        (sk_a.diffie_hellman(&pk_b), sk_b.diffie_hellman(&pk_a))
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_roundtrip() {
+        let mut rng = rand::thread_rng();
+        let (shared_a, shared_b) = shared_points(&mut rng);
+        assert_eq!(shared_a, shared_b, "ECDH shared point must agree on both sides");
+
+        let nonce = BaseField::rand(&mut rng);
+        let plaintext: Vec<BaseField> = (0..5).map(|_| BaseField::rand(&mut rng)).collect();
+
+        let ciphertext = encrypt(shared_a, nonce, &plaintext);
+        let decrypted = decrypt(shared_b, nonce, &ciphertext).unwrap();
+
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn test_decrypt_detects_tampering() {
+        let mut rng = rand::thread_rng();
+        let (shared, _) = shared_points(&mut rng);
+        let nonce = BaseField::rand(&mut rng);
+        let plaintext = vec![BaseField::rand(&mut rng), BaseField::rand(&mut rng)];
+
+        let mut ciphertext = encrypt(shared, nonce, &plaintext);
+        ciphertext[0] += BaseField::from(1u64);
+
+        assert!(decrypt(shared, nonce, &ciphertext).is_err());
+    }
+
+    #[test]
+    fn test_decrypt_detects_wrong_shared_point() {
+        let mut rng = rand::thread_rng();
+        let (shared, _) = shared_points(&mut rng);
+        let (other_shared, _) = shared_points(&mut rng);
+        let nonce = BaseField::rand(&mut rng);
+        let plaintext = vec![BaseField::rand(&mut rng)];
+
+        let ciphertext = encrypt(shared, nonce, &plaintext);
+        assert!(decrypt(other_shared, nonce, &ciphertext).is_err());
+    }
+}
@@ -1,14 +1,20 @@
+use ark_ec::twisted_edwards::TECurveConfig;
 use ark_ec::{AffineRepr, CurveGroup};
-use ark_ff::{AdditiveGroup, BigInteger, PrimeField, Zero};
-use ark_serialize::CanonicalSerialize;
+use ark_ff::{AdditiveGroup, BigInteger, Field, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use num_bigint::BigUint;
 use poseidon2::poseidon_btree_hasher;
 use rand::{CryptoRng, Rng};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
+pub mod circom;
+pub mod encryption;
+pub mod musig;
+
 type ScalarField = ark_ed_on_bls12_381::Fr;
 type BaseField = ark_ed_on_bls12_381::Fq;
 type Affine = ark_ed_on_bls12_381::EdwardsAffine;
+type Projective = ark_ed_on_bls12_381::EdwardsProjective;
 // Import the hasher from your previous module
 
 /// A private key for the EdDSA signature scheme on BabyJubjub.
@@ -82,6 +88,14 @@ impl EdDSAPrivateKey {
         EdDSAPublicKey { pk }
     }
 
+    /// Computes the Diffie–Hellman shared point `sk * other.pk` for use with
+    /// [`encryption::encrypt`] / [`encryption::decrypt`].
+    pub fn diffie_hellman(&self, other: &EdDSAPublicKey) -> Affine {
+        let out = self.hash_blake();
+        let sk = Self::derive_sk(&out);
+        (other.pk * sk).into_affine()
+    }
+
     /// Generates a deterministic nonce `r` for the signature.
     ///
     /// Instead of using a random RNG (which fails catastrophically if the RNG is weak),
@@ -132,6 +146,12 @@ impl EdDSAPrivateKey {
 
         EdDSASignature { r: nonce_point, s }
     }
+
+    /// Signs an arbitrary byte string by first reducing it to a field
+    /// element with [`hash_to_field`].
+    pub fn sign_bytes(&self, msg: &[u8]) -> EdDSASignature {
+        self.sign(hash_to_field(msg))
+    }
 }
 
 /// A public key for EdDSA over BabyJubjub.
@@ -183,6 +203,70 @@ impl EdDSAPublicKey {
         result.is_zero()
     }
 
+    /// Verify many signatures at once using a random linear combination.
+    ///
+    /// For `n` entries `(message_i, Pk_i, sig_i)`, draws independent random
+    /// 128-bit scalars `z_i` (with `z_0 = 1`, so an attacker can't trivially
+    /// zero out their own forged entry's contribution to the combination) and
+    /// checks the single cofactored equation:
+    ///
+    /// `8 * ( (Σ z_i·s_i)·G − Σ z_i·R_i − Σ z_i·c_i·Pk_i ) == 0`
+    ///
+    /// This costs one multiply by `G` plus `2n` variable-base multiplies
+    /// (`z_i·R_i` and `(z_i·c_i)·Pk_i`), accumulated with plain group
+    /// additions rather than a multi-scalar multiplication; `verify` in a loop
+    /// costs `n` multiplies by `G` and `n` by `Pk_i`. The check itself is the
+    /// standard random-linear-combination batch check used by ed25519-dalek's
+    /// `batch` feature, adapted to Jubjub's cofactor of 8.
+    pub fn verify_batch<R: Rng + CryptoRng>(
+        entries: &[(BaseField, EdDSAPublicKey, EdDSASignature)],
+        rng: &mut R,
+    ) -> bool {
+        if entries.is_empty() {
+            return true;
+        }
+
+        let mut s_acc = ScalarField::ZERO;
+        let mut r_acc = Projective::zero();
+        let mut pk_acc = Projective::zero();
+
+        for (i, (message, pk, signature)) in entries.iter().enumerate() {
+            // Same per-entry pre-checks as `verify`, so a malformed entry
+            // short-circuits to `false` before it ever reaches the accumulator.
+            let s_biguint: BigUint = signature.s.into();
+            if s_biguint >= ScalarField::MODULUS.into() {
+                return false;
+            }
+            if pk.pk.is_zero() || !pk.pk.is_on_curve() || !signature.r.is_on_curve() {
+                return false;
+            }
+
+            let challenge = challenge_hash(*message, signature.r, pk.pk);
+            let c = convert_base_to_scalar(challenge);
+
+            let z = if i == 0 {
+                ScalarField::ONE
+            } else {
+                let mut z_bytes = [0u8; 16];
+                rng.fill_bytes(&mut z_bytes);
+                ScalarField::from_le_bytes_mod_order(&z_bytes)
+            };
+
+            s_acc += z * signature.s;
+            r_acc += signature.r * z;
+            pk_acc += pk.pk * (z * c);
+        }
+
+        let mut result = (Affine::generator() * s_acc) - r_acc - pk_acc;
+
+        // Multiply by Cofactor (8), same as the single-signature `verify`.
+        result.double_in_place();
+        result.double_in_place();
+        result.double_in_place();
+
+        result.is_zero()
+    }
+
     pub fn to_compressed_bytes(&self) -> eyre::Result<[u8; 32]> {
         let mut buf = Vec::new();
         self.pk
@@ -207,6 +291,18 @@ impl EdDSAPublicKey {
         }
         Ok(bytes)
     }
+
+    /// Recover a public key from its compressed encoding (the inverse of
+    /// `to_compressed_bytes`).
+    pub fn from_compressed_bytes(bytes: &[u8; 32]) -> eyre::Result<Self> {
+        let pk = decompress_point(bytes)?;
+        Ok(Self { pk })
+    }
+
+    /// Verifies a signature produced by [`EdDSAPrivateKey::sign_bytes`].
+    pub fn verify_bytes(&self, msg: &[u8], signature: &EdDSASignature) -> bool {
+        self.verify(hash_to_field(msg), signature)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -241,6 +337,76 @@ impl EdDSASignature {
         }
         Ok(bytes)
     }
+
+    /// Recover a signature from its compressed encoding (the inverse of
+    /// `to_compressed_bytes`).
+    pub fn from_compressed_bytes(bytes: &[u8; 64]) -> eyre::Result<Self> {
+        let r_bytes: [u8; 32] = bytes[0..32]
+            .try_into()
+            .expect("slice of length 32 converts to [u8; 32]");
+        let r = decompress_point(&r_bytes)?;
+
+        let s = ScalarField::deserialize_compressed(&bytes[32..64])
+            .map_err(|e| eyre::eyre!("invalid signature scalar encoding: {e:?}"))?;
+        // `CanonicalDeserialize` already rejects bytes whose big-integer value
+        // is >= the field modulus, but check explicitly to keep the same
+        // "s < L" guard `verify` relies on, in case that assumption ever changes.
+        let s_biguint: BigUint = s.into();
+        if s_biguint >= ScalarField::MODULUS.into() {
+            return Err(eyre::eyre!("signature scalar s is out of range"));
+        }
+
+        Ok(Self { r, s })
+    }
+}
+
+/// Decompresses a y-coordinate + sign-bit encoding (as produced by
+/// `EdDSAPublicKey::to_compressed_bytes` / `EdDSASignature::to_compressed_bytes`)
+/// back into a curve point.
+///
+/// Clears the sign bit from byte 31 to read `y`, then recovers `x` from the
+/// twisted Edwards curve equation `a·x² + y² = 1 + d·x²·y²`, i.e.
+/// `x² = (y² − 1) / (d·y² − a)`, taking the field square root and negating it
+/// if its parity doesn't match the stored sign bit.
+fn decompress_point(bytes: &[u8; 32]) -> eyre::Result<Affine> {
+    let sign = bytes[31] & 0x80 != 0;
+    let mut y_bytes = *bytes;
+    y_bytes[31] &= 0x7F;
+
+    let y = BaseField::deserialize_compressed(&y_bytes[..])
+        .map_err(|e| eyre::eyre!("invalid y-coordinate encoding: {e:?}"))?;
+
+    let y2 = y.square();
+    let numerator = y2 - BaseField::ONE;
+    let denominator =
+        ark_ed_on_bls12_381::EdwardsConfig::COEFF_D * y2 - ark_ed_on_bls12_381::EdwardsConfig::COEFF_A;
+    let x2 = numerator
+        * denominator
+            .inverse()
+            .ok_or_else(|| eyre::eyre!("denominator vanishes for this y-coordinate"))?;
+    let mut x = x2
+        .sqrt()
+        .ok_or_else(|| eyre::eyre!("y-coordinate does not correspond to a point on the curve"))?;
+
+    // RFC 8032's canonicality rule: x = 0 is even, so a sign bit of 1 paired
+    // with x = 0 can never be produced by an honest encoder (ours included)
+    // and must be rejected, or two distinct byte strings would decode to the
+    // same point.
+    if x.is_zero() && sign {
+        return Err(eyre::eyre!(
+            "non-canonical encoding: x = 0 with sign bit set"
+        ));
+    }
+
+    if x.into_bigint().is_odd() != sign {
+        x = -x;
+    }
+
+    let point = Affine::new_unchecked(x, y);
+    if !point.is_on_curve() {
+        return Err(eyre::eyre!("decompressed point is not on the curve"));
+    }
+    Ok(point)
 }
 
 /// Computes the Fiat-Shamir challenge.
@@ -266,6 +432,24 @@ fn challenge_hash(message: BaseField, nonce_r: Affine, pk: Affine) -> BaseField
     BaseField::from_be_bytes_mod_order(&result)
 }
 
+const HASH_TO_FIELD_DST: &[u8] = b"TokamakAuth\xE2\x80\x91EDDSA\xE2\x80\x91H2F\xE2\x80\x91v1";
+
+/// Hashes an arbitrary byte string down to a single `BaseField` element.
+///
+/// The input is length-prefixed and domain-separated before being chunked
+/// into 32-byte field-sized limbs and folded with `poseidon_btree_hasher`, so
+/// two distinct byte strings never collide after the final reduction mod the
+/// field order (mirrors semaphore-rs's `hash_to_field` helper).
+pub fn hash_to_field(msg: &[u8]) -> BaseField {
+    let mut buf = Vec::with_capacity(HASH_TO_FIELD_DST.len() + 8 + msg.len());
+    buf.extend_from_slice(HASH_TO_FIELD_DST);
+    buf.extend_from_slice(&(msg.len() as u64).to_be_bytes());
+    buf.extend_from_slice(msg);
+
+    let digest = poseidon_btree_hasher(&buf).expect("Poseidon hash failed");
+    BaseField::from_be_bytes_mod_order(&digest)
+}
+
 /// Converts a BaseField element (Poseidon Output) to a ScalarField element.
 ///
 ///
@@ -276,6 +460,20 @@ pub(crate) fn convert_base_to_scalar(f: BaseField) -> ScalarField {
     ScalarField::from_le_bytes_mod_order(&bytes)
 }
 
+/// Converts a `BaseField` element to the `BigInt` representation the
+/// `poseidon2` field-element hashers (`poseidon_n`, `poseidon_n2x_compress`)
+/// expect as input.
+pub(crate) fn field_to_bigint(f: BaseField) -> num_bigint::BigInt {
+    num_bigint::BigInt::from_bytes_le(num_bigint::Sign::Plus, &f.into_bigint().to_bytes_le())
+}
+
+/// Converts a `BigInt` Poseidon output back into a `BaseField` element,
+/// reducing modulo the field order.
+pub(crate) fn bigint_to_field(n: &num_bigint::BigInt) -> BaseField {
+    let (_, bytes) = n.to_bytes_le();
+    BaseField::from_le_bytes_mod_order(&bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -318,6 +516,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_verify_batch() {
+        let mut rng = rand::thread_rng();
+
+        let entries: Vec<_> = (0..8)
+            .map(|_| {
+                let sk = EdDSAPrivateKey::random(&mut rng);
+                let pk = sk.public();
+                let message = BaseField::rand(&mut rng);
+                let signature = sk.sign(message);
+                (message, pk, signature)
+            })
+            .collect();
+
+        assert!(
+            EdDSAPublicKey::verify_batch(&entries, &mut rng),
+            "Batch of valid signatures should verify"
+        );
+
+        // Corrupt one entry's message; the batch check must now fail.
+        let mut tampered = entries.clone();
+        tampered[3].0 = BaseField::rand(&mut rng);
+        assert!(
+            !EdDSAPublicKey::verify_batch(&tampered, &mut rng),
+            "Batch containing a tampered entry should fail"
+        );
+
+        assert!(EdDSAPublicKey::verify_batch(&[], &mut rng), "Empty batch is vacuously valid");
+    }
+
     #[test]
     fn test_serialization_roundtrip() {
         let mut rng = rand::thread_rng();
@@ -335,6 +563,68 @@ mod tests {
         );
         println!("let public_key= {:?}", hex::encode(pk_bytes));
         println!("let signature = {:?}", hex::encode(sig_bytes));
+
+        // The roundtrip actually parses back to the original values now.
+        let decoded_pk = EdDSAPublicKey::from_compressed_bytes(&pk_bytes).unwrap();
+        assert_eq!(decoded_pk, pk);
+
+        let decoded_sig = EdDSASignature::from_compressed_bytes(&sig_bytes).unwrap();
+        assert_eq!(decoded_sig, sig);
+
+        assert!(decoded_pk.verify(msg, &decoded_sig));
+    }
+
+    #[test]
+    fn test_from_compressed_bytes_rejects_out_of_range_y() {
+        // 0xFF * 32 is far larger than the field modulus, so this can never
+        // be a valid y-coordinate encoding.
+        let bytes = [0xFFu8; 32];
+        assert!(EdDSAPublicKey::from_compressed_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_from_compressed_bytes_rejects_non_canonical_zero_x() {
+        // y = 1 gives x = 0 (even), so encoding it with the sign bit set is a
+        // non-canonical byte string that must be rejected rather than
+        // silently decoding to the same point as sign = 0.
+        let mut buf = Vec::new();
+        BaseField::ONE.serialize_compressed(&mut buf).unwrap();
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&buf);
+        bytes[31] |= 0x80;
+
+        assert!(EdDSAPublicKey::from_compressed_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_sign_bytes_and_verify_bytes_flow() {
+        let mut rng = rand::thread_rng();
+        let sk = EdDSAPrivateKey::random(&mut rng);
+        let pk = sk.public();
+
+        let msg = b"arbitrary length message, not pre-reduced to a field element";
+        let signature = sk.sign_bytes(msg);
+
+        assert!(pk.verify_bytes(msg, &signature));
+        assert!(!pk.verify_bytes(b"a different message", &signature));
+    }
+
+    #[test]
+    fn test_hash_to_field_domain_separates_length_ambiguous_inputs() {
+        // `poseidon_btree_hasher` pads the chunk count with zero chunks (see
+        // `test_poseidon_btree_hasher`), so without a length prefix a message
+        // and the same message followed by a 32-byte zero chunk would hash
+        // alike; the length prefix must keep them apart.
+        let a = hash_to_field(b"ab");
+        let b = hash_to_field(b"c");
+        let combined_ab_c = hash_to_field(b"abc");
+        assert_ne!(a, combined_ab_c);
+        assert_ne!(b, combined_ab_c);
+
+        let msg = vec![7u8; 64];
+        let mut msg_zero_chunk = msg.clone();
+        msg_zero_chunk.extend_from_slice(&[0u8; 32]);
+        assert_ne!(hash_to_field(&msg), hash_to_field(&msg_zero_chunk));
     }
     #[test]
     fn test_empty_input() {
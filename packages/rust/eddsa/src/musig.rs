@@ -0,0 +1,281 @@
+//! Schnorr multi-signatures (MuSig-style key and signature aggregation).
+//!
+//! A set of signers can jointly produce one [`EdDSASignature`] that verifies
+//! against a single aggregated [`EdDSAPublicKey`] via the unchanged
+//! `EdDSAPublicKey::verify`. This implements the MuSig protocol over Jubjub:
+//!
+//! 1. Keys are aggregated as `X = Σ a_i·Pk_i`, where `a_i = Poseidon(L, Pk_i)`
+//!    and `L = Poseidon(Pk_1..Pk_n)` binds the full key set, defeating
+//!    rogue-key attacks (a signer can't pick their key *after* seeing the
+//!    others' to cancel out the aggregate).
+//! 2. Each signer commits to a nonce with [`NonceCommitment::commit`] and only
+//!    reveals `R_i = r_i·G` ([`NonceCommitment::reveal`]) once every other
+//!    signer's commitment has been collected and checked with
+//!    [`verify_nonce_reveal`]. Revealing nonces up front instead would let a
+//!    coalition of signers solve for an `R_i` that lets them forge a
+//!    signature (Drijvers et al., "On the Security of Two-Round Multi-
+//!    Signatures"), which the commit-then-reveal round closes.
+//! 3. The aggregate nonce is `R = Σ R_i`, the common challenge is
+//!    `c = challenge_hash(msg, R, X)`, and each signer returns
+//!    `s_i = r_i + c·a_i·sk_i`. The combined `s = Σ s_i`, paired with `R`,
+//!    forms a standard [`EdDSASignature`].
+
+use crate::{
+    bigint_to_field, challenge_hash, convert_base_to_scalar, field_to_bigint, Affine, BaseField,
+    EdDSAPrivateKey, EdDSAPublicKey, EdDSASignature, Projective, ScalarField,
+};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, PrimeField, UniformRand, Zero};
+use ark_serialize::CanonicalSerialize;
+use poseidon2::poseidon_n;
+use rand::{CryptoRng, Rng};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+const DOMAIN_KEY_BINDING: &[u8] = b"TokamakAuth\xE2\x80\x91EDDSA\xE2\x80\x91MUSIG\xE2\x80\x91L\xE2\x80\x91v1";
+const DOMAIN_KEY_COEFF: &[u8] = b"TokamakAuth\xE2\x80\x91EDDSA\xE2\x80\x91MUSIG\xE2\x80\x91A\xE2\x80\x91v1";
+
+fn domain_key_binding() -> BaseField {
+    BaseField::from_be_bytes_mod_order(DOMAIN_KEY_BINDING)
+}
+
+fn domain_key_coeff() -> BaseField {
+    BaseField::from_be_bytes_mod_order(DOMAIN_KEY_COEFF)
+}
+
+/// A signer's round-1 state: the secret nonce scalar `r_i`, kept until the
+/// signing round, and the public nonce point `R_i = r_i·G` that is only
+/// revealed after every signer's commitment has been exchanged.
+///
+/// Security Note: like `EdDSAPrivateKey`, the secret scalar is kept as raw
+/// bytes behind `ZeroizeOnDrop` so it's wiped from memory once this goes out
+/// of scope. Leaking `r_i` after it's used in a partial signature lets an
+/// attacker recover the signer's key share from `s_i = r_i + c·a_i·sk_i`.
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct NonceCommitment {
+    r_scalar_bytes: [u8; 32],
+    #[zeroize(skip)]
+    r_point: Affine,
+}
+
+impl NonceCommitment {
+    /// Generates a fresh random nonce for round 1.
+    pub fn generate<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        let r_scalar = ScalarField::rand(rng);
+        let r_point = (Affine::generator() * r_scalar).into_affine();
+
+        let mut r_scalar_bytes = [0u8; 32];
+        r_scalar_bytes.copy_from_slice(&r_scalar.into_bigint().to_bytes_le());
+
+        Self {
+            r_scalar_bytes,
+            r_point,
+        }
+    }
+
+    fn r_scalar(&self) -> ScalarField {
+        ScalarField::from_le_bytes_mod_order(&self.r_scalar_bytes)
+    }
+
+    /// The hash commitment `H(R_i)` to broadcast before any nonce point is
+    /// revealed. Every other signer must receive and store this before this
+    /// signer reveals `R_i`.
+    pub fn commit(&self) -> [u8; 32] {
+        hash_nonce_point(self.r_point)
+    }
+
+    /// Reveals the public nonce point `R_i`, once every signer's commitment
+    /// has been collected.
+    pub fn reveal(&self) -> Affine {
+        self.r_point
+    }
+}
+
+fn hash_nonce_point(r: Affine) -> [u8; 32] {
+    let mut buf = Vec::new();
+    r.serialize_compressed(&mut buf)
+        .expect("affine point serialization cannot fail");
+
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&buf);
+    *hasher.finalize().as_bytes()
+}
+
+/// Checks a signer's revealed nonce point against their round-1 commitment.
+/// Callers must verify every signer's reveal this way before calling
+/// [`aggregate_nonces`], otherwise the commit-reveal round provides no
+/// protection against rogue-nonce attacks.
+pub fn verify_nonce_reveal(commitment: &[u8; 32], revealed: Affine) -> bool {
+    hash_nonce_point(revealed) == *commitment
+}
+
+/// A signer's round-2 contribution `s_i` to the aggregated signature.
+#[derive(Clone, Copy)]
+pub struct PartialSignature(pub ScalarField);
+
+/// Computes `L = Poseidon(Pk_1.x, Pk_1.y, .., Pk_n.x, Pk_n.y)`, binding the
+/// full key set ahead of the per-signer coefficients.
+fn binding_factor(pks: &[EdDSAPublicKey]) -> BaseField {
+    let mut inputs = vec![field_to_bigint(domain_key_binding())];
+    inputs.extend(
+        pks.iter()
+            .flat_map(|pk| [field_to_bigint(pk.pk.x), field_to_bigint(pk.pk.y)]),
+    );
+    bigint_to_field(&poseidon_n(&inputs).expect("Poseidon hash failed"))
+}
+
+/// Computes each signer's key-aggregation coefficient `a_i = Poseidon(L, Pk_i)`.
+pub fn key_coefficients(pks: &[EdDSAPublicKey]) -> Vec<ScalarField> {
+    let l = binding_factor(pks);
+    pks.iter()
+        .map(|pk| {
+            let inputs = vec![
+                field_to_bigint(domain_key_coeff()),
+                field_to_bigint(l),
+                field_to_bigint(pk.pk.x),
+                field_to_bigint(pk.pk.y),
+            ];
+            let hash = poseidon_n(&inputs).expect("Poseidon hash failed");
+            convert_base_to_scalar(bigint_to_field(&hash))
+        })
+        .collect()
+}
+
+/// Aggregates a set of public keys into a single MuSig key `X = Σ a_i·Pk_i`.
+pub fn aggregate_keys(pks: &[EdDSAPublicKey]) -> EdDSAPublicKey {
+    let coefficients = key_coefficients(pks);
+    let agg = pks
+        .iter()
+        .zip(coefficients.iter())
+        .fold(Projective::zero(), |acc, (pk, a)| acc + pk.pk * a);
+    EdDSAPublicKey {
+        pk: agg.into_affine(),
+    }
+}
+
+/// Aggregates per-signer revealed nonce points into the round's aggregate
+/// nonce `R = Σ R_i`. Every entry must already have passed
+/// [`verify_nonce_reveal`] against its round-1 commitment.
+pub fn aggregate_nonces(revealed_nonces: &[Affine]) -> Affine {
+    revealed_nonces
+        .iter()
+        .fold(Projective::zero(), |acc, r| acc + r)
+        .into_affine()
+}
+
+/// Produces signer `i`'s partial signature `s_i = r_i + c·a_i·sk_i`.
+///
+/// Consumes `nonce` so a single [`NonceCommitment`] can't accidentally be
+/// reused to sign two different messages, which would leak the signer's
+/// private key. `coefficient` is signer `i`'s entry from
+/// [`key_coefficients`], and `aggregated_nonce`/`aggregated_pk` are the
+/// round's `R` and `X`.
+pub fn sign_partial(
+    sk: &EdDSAPrivateKey,
+    nonce: NonceCommitment,
+    message: BaseField,
+    aggregated_nonce: Affine,
+    aggregated_pk: &EdDSAPublicKey,
+    coefficient: ScalarField,
+) -> PartialSignature {
+    let out = sk.hash_blake();
+    let my_sk = EdDSAPrivateKey::derive_sk(&out);
+
+    let challenge = challenge_hash(message, aggregated_nonce, aggregated_pk.pk);
+    let c = convert_base_to_scalar(challenge);
+
+    PartialSignature(nonce.r_scalar() + c * coefficient * my_sk)
+}
+
+/// Combines partial signatures into the final aggregated signature
+/// `s = Σ s_i`, paired with the round's aggregate nonce `R`.
+pub fn aggregate_partial_sigs(
+    aggregated_nonce: Affine,
+    partials: &[PartialSignature],
+) -> EdDSASignature {
+    let s = partials
+        .iter()
+        .fold(ScalarField::zero(), |acc, p| acc + p.0);
+    EdDSASignature {
+        r: aggregated_nonce,
+        s,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_musig_two_of_two_roundtrip() {
+        let mut rng = rand::thread_rng();
+
+        let sk1 = EdDSAPrivateKey::random(&mut rng);
+        let sk2 = EdDSAPrivateKey::random(&mut rng);
+        let pks = vec![sk1.public(), sk2.public()];
+
+        let agg_pk = aggregate_keys(&pks);
+        let coefficients = key_coefficients(&pks);
+
+        let nonce1 = NonceCommitment::generate(&mut rng);
+        let nonce2 = NonceCommitment::generate(&mut rng);
+
+        // Round 1: exchange commitments, then check each revealed nonce
+        // against its commitment before aggregating.
+        let commit1 = nonce1.commit();
+        let commit2 = nonce2.commit();
+        assert!(verify_nonce_reveal(&commit1, nonce1.reveal()));
+        assert!(verify_nonce_reveal(&commit2, nonce2.reveal()));
+
+        let agg_nonce = aggregate_nonces(&[nonce1.reveal(), nonce2.reveal()]);
+
+        let message = BaseField::rand(&mut rng);
+
+        let partial1 = sign_partial(&sk1, nonce1, message, agg_nonce, &agg_pk, coefficients[0]);
+        let partial2 = sign_partial(&sk2, nonce2, message, agg_nonce, &agg_pk, coefficients[1]);
+
+        let signature = aggregate_partial_sigs(agg_nonce, &[partial1, partial2]);
+
+        assert!(agg_pk.verify(message, &signature));
+    }
+
+    #[test]
+    fn test_musig_three_signers_wrong_message_fails() {
+        let mut rng = rand::thread_rng();
+
+        let sks: Vec<_> = (0..3).map(|_| EdDSAPrivateKey::random(&mut rng)).collect();
+        let pks: Vec<_> = sks.iter().map(|sk| sk.public()).collect();
+
+        let agg_pk = aggregate_keys(&pks);
+        let coefficients = key_coefficients(&pks);
+
+        let nonces: Vec<_> = (0..3).map(|_| NonceCommitment::generate(&mut rng)).collect();
+        let revealed: Vec<_> = nonces.iter().map(|n| n.reveal()).collect();
+        let agg_nonce = aggregate_nonces(&revealed);
+
+        let message = BaseField::rand(&mut rng);
+        let partials: Vec<_> = sks
+            .into_iter()
+            .zip(nonces)
+            .zip(coefficients)
+            .map(|((sk, nonce), coefficient)| {
+                sign_partial(&sk, nonce, message, agg_nonce, &agg_pk, coefficient)
+            })
+            .collect();
+
+        let signature = aggregate_partial_sigs(agg_nonce, &partials);
+        assert!(agg_pk.verify(message, &signature));
+
+        let wrong_message = BaseField::rand(&mut rng);
+        assert!(!agg_pk.verify(wrong_message, &signature));
+    }
+
+    #[test]
+    fn test_verify_nonce_reveal_rejects_mismatched_point() {
+        let mut rng = rand::thread_rng();
+        let nonce1 = NonceCommitment::generate(&mut rng);
+        let nonce2 = NonceCommitment::generate(&mut rng);
+
+        let commit1 = nonce1.commit();
+        assert!(!verify_nonce_reveal(&commit1, nonce2.reveal()));
+    }
+}